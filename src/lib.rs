@@ -23,6 +23,12 @@ pub enum Error {
     OpenGLContextCreationFailed,
     #[error("Failed to query GPU info")]
     OpenGLQueryFailed,
+    #[error("Requested GPU device index {0} is out of range")]
+    DeviceIndexOutOfRange(usize),
+    #[error("Invalid {0} value {1:?}: expected a device index")]
+    InvalidDeviceIndexOverride(&'static str, String),
+    #[error("No windowing backend available to determine presentation support")]
+    PresentationBackendUnavailable,
 }
 
 impl Error {
@@ -40,34 +46,506 @@ pub enum GPUKind {
     Unknown,
 }
 
+/// Known Vulkan driver implementations, as reported by
+/// `VkPhysicalDeviceDriverProperties::driverID`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriverId {
+    AmdProprietary,
+    AmdOpenSource,
+    MesaRadv,
+    NvidiaProprietary,
+    IntelProprietaryWindows,
+    IntelOpenSourceMesa,
+    ImaginationProprietary,
+    QualcommProprietary,
+    ArmProprietary,
+    GoogleSwiftshader,
+    GgpProprietary,
+    BroadcomProprietary,
+    MesaLlvmpipe,
+    Moltenvk,
+    Unknown,
+}
+
+impl From<vk::DriverId> for DriverId {
+    fn from(id: vk::DriverId) -> Self {
+        match id {
+            vk::DriverId::AMD_PROPRIETARY => DriverId::AmdProprietary,
+            vk::DriverId::AMD_OPEN_SOURCE => DriverId::AmdOpenSource,
+            vk::DriverId::MESA_RADV => DriverId::MesaRadv,
+            vk::DriverId::NVIDIA_PROPRIETARY => DriverId::NvidiaProprietary,
+            vk::DriverId::INTEL_PROPRIETARY_WINDOWS => DriverId::IntelProprietaryWindows,
+            vk::DriverId::INTEL_OPEN_SOURCE_MESA => DriverId::IntelOpenSourceMesa,
+            vk::DriverId::IMAGINATION_PROPRIETARY => DriverId::ImaginationProprietary,
+            vk::DriverId::QUALCOMM_PROPRIETARY => DriverId::QualcommProprietary,
+            vk::DriverId::ARM_PROPRIETARY => DriverId::ArmProprietary,
+            vk::DriverId::GOOGLE_SWIFTSHADER => DriverId::GoogleSwiftshader,
+            vk::DriverId::GGP_PROPRIETARY => DriverId::GgpProprietary,
+            vk::DriverId::BROADCOM_PROPRIETARY => DriverId::BroadcomProprietary,
+            vk::DriverId::MESA_LLVMPIPE => DriverId::MesaLlvmpipe,
+            vk::DriverId::MOLTENVK => DriverId::Moltenvk,
+            _ => DriverId::Unknown,
+        }
+    }
+}
+
+impl GPUKind {
+    /// Selection priority, highest first: a caller that wants "the best" GPU
+    /// prefers a discrete device over an integrated one, and so on.
+    fn priority(self) -> u8 {
+        match self {
+            GPUKind::Discrete => 4,
+            GPUKind::Integrated => 3,
+            GPUKind::Virtual => 2,
+            GPUKind::CPU => 1,
+            GPUKind::Unknown => 0,
+        }
+    }
+}
+
+/// Comparison operator used by [`GpuMatchRule`] to test a device's raw driver
+/// version against a threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionCmp {
+    Lt,
+    Le,
+    Eq,
+    Ne,
+    Ge,
+    Gt,
+}
+
+impl VersionCmp {
+    fn eval(self, lhs: u32, rhs: u32) -> bool {
+        match self {
+            VersionCmp::Lt => lhs < rhs,
+            VersionCmp::Le => lhs <= rhs,
+            VersionCmp::Eq => lhs == rhs,
+            VersionCmp::Ne => lhs != rhs,
+            VersionCmp::Ge => lhs >= rhs,
+            VersionCmp::Gt => lhs > rhs,
+        }
+    }
+}
+
+/// A predicate over a [`GPU`], keyed on stable numeric identity rather than
+/// fragile name substrings.
+///
+/// Every field is optional and acts as a wildcard when `None`; a rule matches a
+/// device only when all of its populated constraints hold. This mirrors the
+/// GPU-config/blocklist files shipped by browsers and emulators, letting
+/// applications gate features on known-bad GPU/driver combinations.
+#[derive(Debug, Clone, Default)]
+pub struct GpuMatchRule {
+    /// Require an exact PCI vendor ID.
+    pub vendor_id: Option<u32>,
+    /// Require the PCI device ID to fall within an inclusive `(min, max)` range.
+    pub device_id_range: Option<(u32, u32)>,
+    /// Require a specific device kind.
+    pub kind: Option<GPUKind>,
+    /// Compare the device's raw driver version against a threshold.
+    pub driver_version: Option<(VersionCmp, u32)>,
+}
+
+/// Environment variable that forces a specific physical-device index, bypassing
+/// the type-priority ranking used by [`select_preferred_gpu`].
+pub const DEVICE_INDEX_ENV: &str = "GPU_INFO_DEVICE_INDEX";
+
 #[derive(Debug, Clone)]
 pub struct GPU {
     pub kind: GPUKind,
     pub name: String,
     pub vendor: String,
+    /// PCI vendor ID (e.g. `0x10DE` for NVIDIA), a stable numeric identifier.
+    pub vendor_id: u32,
+    /// PCI device ID, unique within a vendor's product line.
+    pub device_id: u32,
     pub driver_version: String,
+    /// Raw `VkPhysicalDeviceProperties::driverVersion` before vendor decoding,
+    /// so callers that disagree with our formatting can re-render it.
+    pub driver_version_raw: u32,
+    /// Driver identity as reported by `VK_KHR_driver_properties`.
+    ///
+    /// `None` on Vulkan 1.0-only drivers that do not expose the extension.
+    pub driver_id: Option<DriverId>,
+    /// Human-readable driver name (e.g. `"NVIDIA"`, `"radv"`), when available.
+    pub driver_name: Option<String>,
+    /// Free-form driver version/build string reported alongside `driver_name`.
+    pub driver_info: Option<String>,
     /// 0 is means unknown or not available
     pub vram: u64,
+    /// Currently used VRAM across DEVICE_LOCAL heaps, in MB.
+    ///
+    /// `None` unless the device advertises `VK_EXT_memory_budget`.
+    pub vram_used: Option<u64>,
+    /// Current VRAM budget (how much the implementation is willing to let this
+    /// process allocate) across DEVICE_LOCAL heaps, in MB.
+    ///
+    /// `None` unless the device advertises `VK_EXT_memory_budget`.
+    pub vram_budget: Option<u64>,
     // pub max_resolution: Resolution,
     // pub current_resolution: Resolution,
     pub clock_speed: Option<u32>,
     pub temperature: Option<u32>,
 }
 
+/// Options controlling which physical devices [`retrieve_gpu_info_via_vk_with_options`]
+/// returns.
+///
+/// The [`Default`] value preserves the historical "return every enumerated
+/// device" behavior.
+#[derive(Debug, Clone, Default)]
+pub struct GpuQueryOptions {
+    /// Skip devices whose `apiVersion` is below this packed Vulkan version
+    /// (e.g. [`vk::API_VERSION_1_2`](ash::vk::API_VERSION_1_2)).
+    pub min_api_version: Option<u32>,
+    /// Keep only devices that expose a queue family with presentation support.
+    ///
+    /// Presentation is probed through the platform `*_presentation_support`
+    /// entry point; on Linux this requires the opt-in `presentation` feature
+    /// (which links libX11) and a reachable X display. When this is set but no
+    /// windowing backend is available — headless, no `$DISPLAY`, pure-Wayland
+    /// without XWayland, or the feature is disabled — the query fails with
+    /// [`Error::PresentationBackendUnavailable`] rather than returning an empty
+    /// list, so callers can tell "no windowing system" apart from "no GPUs".
+    pub require_present: bool,
+}
+
+impl GPU {
+    /// Test this device against a [`GpuMatchRule`].
+    ///
+    /// Returns `true` only when every populated constraint of `rule` holds, so
+    /// a default (all-`None`) rule matches every device.
+    pub fn matches(&self, rule: &GpuMatchRule) -> bool {
+        if let Some(vendor_id) = rule.vendor_id {
+            if self.vendor_id != vendor_id {
+                return false;
+            }
+        }
+        if let Some((min, max)) = rule.device_id_range {
+            if self.device_id < min || self.device_id > max {
+                return false;
+            }
+        }
+        if let Some(kind) = rule.kind {
+            if self.kind != kind {
+                return false;
+            }
+        }
+        if let Some((cmp, version)) = rule.driver_version {
+            if !cmp.eval(self.driver_version_raw, version) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Enumerate GPUs via Vulkan with the default options (every device).
 pub fn retrieve_gpu_info_via_vk() -> Result<Vec<GPU>, Error> {
+    retrieve_gpu_info_via_vk_with_options(&GpuQueryOptions::default())
+}
+
+/// Which platform surface extensions the instance was actually created with.
+///
+/// Presentation-support entry points must not be called unless their extension
+/// was enabled, and we only enable the ones the loader reports as available.
+#[derive(Debug, Clone, Copy, Default)]
+struct SurfaceSupport {
+    #[cfg(target_os = "windows")]
+    win32: bool,
+    #[cfg(all(
+        feature = "presentation",
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "android")
+    ))]
+    xlib: bool,
+}
+
+/// Candidate platform surface extensions for the current target.
+///
+/// The Xlib entry is only offered when the `presentation` feature is enabled,
+/// since that is the only backend that pulls in a windowing-system dependency.
+fn wanted_surface_extensions() -> Vec<&'static CStr> {
+    #[allow(unused_mut)]
+    let mut extensions = vec![ash::khr::surface::NAME];
+    #[cfg(target_os = "windows")]
+    extensions.push(ash::khr::win32_surface::NAME);
+    #[cfg(all(
+        feature = "presentation",
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "android")
+    ))]
+    extensions.push(ash::khr::xlib_surface::NAME);
+    extensions
+}
+
+/// Instance extension names the loader advertises.
+fn available_instance_extensions(entry: &ash::Entry) -> Vec<std::ffi::CString> {
+    match unsafe { entry.enumerate_instance_extension_properties(None) } {
+        Ok(props) => props
+            .iter()
+            .map(|p| unsafe { CStr::from_ptr(p.extension_name.as_ptr()) }.to_owned())
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Intersect the wanted surface extensions with what the loader actually
+/// supports, returning the enabled name pointers plus which were turned on.
+fn enabled_surface_extensions(
+    available: &[std::ffi::CString],
+) -> (Vec<*const std::os::raw::c_char>, SurfaceSupport) {
+    let mut pointers = Vec::new();
+    #[allow(unused_mut)]
+    let mut support = SurfaceSupport::default();
+    for name in wanted_surface_extensions() {
+        if !available.iter().any(|a| a.as_c_str() == name) {
+            continue;
+        }
+        pointers.push(name.as_ptr());
+        #[cfg(target_os = "windows")]
+        if name == ash::khr::win32_surface::NAME {
+            support.win32 = true;
+        }
+        #[cfg(all(
+            feature = "presentation",
+            unix,
+            not(target_os = "macos"),
+            not(target_os = "android")
+        ))]
+        if name == ash::khr::xlib_surface::NAME {
+            support.xlib = true;
+        }
+    }
+    (pointers, support)
+}
+
+/// A live presentation probe backed by whichever surface extension the instance
+/// was created with, resolved once up front so the per-device query is cheap.
+///
+/// Owns any windowing-system connection it opened (e.g. the X11 display) and
+/// releases it on drop, so a probe lives exactly as long as the enumeration
+/// that uses it.
+#[derive(Default)]
+struct PresentProbe {
+    #[cfg(target_os = "windows")]
+    win32: bool,
+    #[cfg(all(
+        feature = "presentation",
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "android")
+    ))]
+    xlib: Option<(*mut vk::Display, vk::VisualID)>,
+}
+
+impl PresentProbe {
+    /// Resolve a probe from the enabled surface extensions, opening a display
+    /// connection where the backend needs one.
+    #[allow(unused_variables)]
+    fn resolve(support: SurfaceSupport) -> Self {
+        #[allow(unused_mut)]
+        let mut probe = PresentProbe::default();
+        #[cfg(target_os = "windows")]
+        {
+            probe.win32 = support.win32;
+        }
+        #[cfg(all(
+            feature = "presentation",
+            unix,
+            not(target_os = "macos"),
+            not(target_os = "android")
+        ))]
+        if support.xlib {
+            probe.xlib = xlib_display();
+        }
+        probe
+    }
+
+    /// Whether any windowing backend is actually reachable. `false` means
+    /// presentation cannot be determined at all (headless, no `$DISPLAY`,
+    /// pure-Wayland without XWayland, missing surface extension, or the
+    /// `presentation` feature disabled), as opposed to "no device can present."
+    fn is_available(&self) -> bool {
+        #[cfg(target_os = "windows")]
+        if self.win32 {
+            return true;
+        }
+        #[cfg(all(
+            feature = "presentation",
+            unix,
+            not(target_os = "macos"),
+            not(target_os = "android")
+        ))]
+        if self.xlib.is_some() {
+            return true;
+        }
+        false
+    }
+
+    /// Whether `device` exposes any queue family with real presentation
+    /// support, via the platform `*_presentation_support` entry point.
+    #[allow(unused_variables)]
+    fn device_can_present(
+        &self,
+        entry: &ash::Entry,
+        instance: &ash::Instance,
+        device: vk::PhysicalDevice,
+    ) -> bool {
+        let queue_families =
+            unsafe { instance.get_physical_device_queue_family_properties(device) };
+        let family_count = queue_families.len() as u32;
+
+        #[cfg(target_os = "windows")]
+        if self.win32 {
+            let win32 = ash::khr::win32_surface::Instance::new(entry, instance);
+            return (0..family_count).any(|family_index| unsafe {
+                win32.get_physical_device_win32_presentation_support(device, family_index)
+            });
+        }
+
+        #[cfg(all(
+            feature = "presentation",
+            unix,
+            not(target_os = "macos"),
+            not(target_os = "android")
+        ))]
+        if let Some((display, visual_id)) = self.xlib {
+            // The Xlib presentation-support query needs only a display
+            // connection (no window/surface). Under Wayland it goes through
+            // XWayland.
+            let xlib = ash::khr::xlib_surface::Instance::new(entry, instance);
+            return (0..family_count).any(|family_index| unsafe {
+                xlib.get_physical_device_xlib_presentation_support(
+                    device,
+                    family_index,
+                    &mut *display,
+                    visual_id,
+                )
+            });
+        }
+
+        false
+    }
+}
+
+/// Close the owned X11 display connection opened in [`PresentProbe::resolve`],
+/// so a probe does not leak a connection (fd + memory) per enumeration call.
+#[cfg(all(
+    feature = "presentation",
+    unix,
+    not(target_os = "macos"),
+    not(target_os = "android")
+))]
+impl Drop for PresentProbe {
+    fn drop(&mut self) {
+        use std::os::raw::{c_int, c_void};
+
+        if let Some((display, _)) = self.xlib.take() {
+            #[link(name = "X11")]
+            extern "C" {
+                fn XCloseDisplay(display: *mut c_void) -> c_int;
+            }
+            unsafe {
+                XCloseDisplay(display as *mut c_void);
+            }
+        }
+    }
+}
+
+/// Open the default X display and return it with its default visual id, or
+/// `None` when no display can be reached (e.g. headless CI).
+///
+/// Only compiled with the opt-in `presentation` feature, which is what pulls in
+/// the link-time dependency on libX11; the default build stays windowing-free.
+#[cfg(all(
+    feature = "presentation",
+    unix,
+    not(target_os = "macos"),
+    not(target_os = "android")
+))]
+fn xlib_display() -> Option<(*mut vk::Display, vk::VisualID)> {
+    use std::os::raw::{c_char, c_int, c_void};
+
+    #[link(name = "X11")]
+    extern "C" {
+        fn XOpenDisplay(name: *const c_char) -> *mut c_void;
+        fn XDefaultScreen(display: *mut c_void) -> c_int;
+        fn XDefaultVisual(display: *mut c_void, screen: c_int) -> *mut c_void;
+        fn XVisualIDFromVisual(visual: *mut c_void) -> vk::VisualID;
+    }
+
+    unsafe {
+        let display = XOpenDisplay(std::ptr::null());
+        if display.is_null() {
+            return None;
+        }
+        let screen = XDefaultScreen(display);
+        let visual = XDefaultVisual(display, screen);
+        if visual.is_null() {
+            return None;
+        }
+        let visual_id = XVisualIDFromVisual(visual);
+        Some((display as *mut vk::Display, visual_id))
+    }
+}
+
+/// Enumerate GPUs via Vulkan, filtered by `options`.
+pub fn retrieve_gpu_info_via_vk_with_options(
+    options: &GpuQueryOptions,
+) -> Result<Vec<GPU>, Error> {
     let entry = unsafe { ash::Entry::load() }.map_err(|_| Error::VulkanNotSupported)?;
     let app_name = c"GPUInfoApp";
+
+    // `vkGetPhysicalDeviceProperties2` (and the `pNext` chaining it enables) is
+    // core in Vulkan 1.1. Request 1.1 when the loader supports it so the driver
+    // properties query below has a valid entry point, and fall back to 1.0
+    // otherwise so legacy drivers keep working.
+    let instance_version = match unsafe { entry.try_enumerate_instance_version() } {
+        Ok(Some(version)) => version,
+        Ok(None) | Err(_) => vk::API_VERSION_1_0,
+    };
+    let api_version = if instance_version >= vk::API_VERSION_1_1 {
+        vk::API_VERSION_1_1
+    } else {
+        vk::API_VERSION_1_0
+    };
+    let supports_properties2 = api_version >= vk::API_VERSION_1_1;
+
     let app_info = vk::ApplicationInfo::default()
         .application_name(app_name)
         .application_version(0)
         .engine_name(app_name)
         .engine_version(0)
-        .api_version(vk::API_VERSION_1_0);
+        .api_version(api_version);
 
-    let create_info = vk::InstanceCreateInfo::default().application_info(&app_info);
+    // Presentation probing needs the surface extensions enabled at instance
+    // creation; skip them entirely when the caller does not ask for it, and
+    // only request the ones the loader actually advertises so merely setting
+    // `require_present` can't turn into an `ERROR_EXTENSION_NOT_PRESENT`.
+    let (extension_names, surface_support) = if options.require_present {
+        enabled_surface_extensions(&available_instance_extensions(&entry))
+    } else {
+        (Vec::new(), SurfaceSupport::default())
+    };
+    let create_info = vk::InstanceCreateInfo::default()
+        .application_info(&app_info)
+        .enabled_extension_names(&extension_names);
     let instance = unsafe { entry.create_instance(&create_info, None) }
         .map_err(|e| Error::VulkanOperationFailed(e.to_string()))?;
 
+    // Resolve the presentation backend once. If the caller asked to filter on
+    // presentation but no windowing backend is reachable, surface that as an
+    // explicit error rather than silently returning an empty list that looks
+    // the same as "no GPUs".
+    let present_probe = PresentProbe::resolve(surface_support);
+    if options.require_present && !present_probe.is_available() {
+        return Err(Error::PresentationBackendUnavailable);
+    }
+
     let physical_devices = unsafe { instance.enumerate_physical_devices() }
         .map_err(|e| Error::VulkanOperationFailed(e.to_string()))?;
 
@@ -81,6 +559,18 @@ pub fn retrieve_gpu_info_via_vk() -> Result<Vec<GPU>, Error> {
 
     for device in physical_devices {
         let properties = unsafe { instance.get_physical_device_properties(device) };
+
+        // Apply caller-requested filters before doing the heavier per-device work.
+        if let Some(min_api_version) = options.min_api_version {
+            if properties.api_version < min_api_version {
+                continue;
+            }
+        }
+        if options.require_present
+            && !present_probe.device_can_present(&entry, &instance, device)
+        {
+            continue;
+        }
         let memory_properties = unsafe { instance.get_physical_device_memory_properties(device) };
 
         // Extract GPU properties
@@ -90,20 +580,57 @@ pub fn retrieve_gpu_info_via_vk() -> Result<Vec<GPU>, Error> {
             .to_string();
 
         let vendor_id = properties.vendor_id;
+        let device_id = properties.device_id;
         let vendor_name = match vendor_id {
             0x8086 => "Intel",
             0x10DE => "NVIDIA",
             0x1002 => "AMD",
+            0x13B5 => "ARM",
+            0x5143 => "Qualcomm",
+            0x106B => "Apple",
+            0x1010 => "Imagination",
             _ => "Unknown",
         }
         .to_string();
 
-        let driver_version = format!(
-            "{}.{}.{}",
-            (properties.driver_version >> 22) & 0x3FF,
-            (properties.driver_version >> 12) & 0x3FF,
-            properties.driver_version & 0xFFF
-        );
+        let driver_version_raw = properties.driver_version;
+        let driver_version = format_driver_version(vendor_id, driver_version_raw);
+
+        // Query the richer driver identity through `VK_KHR_driver_properties`
+        // (core in 1.2). This needs both the `vkGetPhysicalDeviceProperties2`
+        // entry point (instance ≥ 1.1) and the device to actually expose the
+        // promoted struct, otherwise the chained struct stays zeroed and we
+        // would report a bogus `Some(DriverId::Unknown)`. `driverName`/
+        // `driverInfo` are fixed-size UTF-8 arrays that must be read with
+        // `CStr::from_ptr` just like `device_name`.
+        let has_driver_properties = properties.api_version >= vk::API_VERSION_1_2
+            || device_has_driver_properties(&instance, device);
+        let (driver_id, driver_name, driver_info) = if supports_properties2 && has_driver_properties
+        {
+            let mut driver_properties = vk::PhysicalDeviceDriverProperties::default();
+            let mut properties2 =
+                vk::PhysicalDeviceProperties2::default().push_next(&mut driver_properties);
+            unsafe { instance.get_physical_device_properties2(device, &mut properties2) };
+
+            let driver_name = unsafe { CStr::from_ptr(driver_properties.driver_name.as_ptr()) }
+                .to_str()
+                .ok()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string());
+            let driver_info = unsafe { CStr::from_ptr(driver_properties.driver_info.as_ptr()) }
+                .to_str()
+                .ok()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string());
+
+            (
+                Some(DriverId::from(driver_properties.driver_id)),
+                driver_name,
+                driver_info,
+            )
+        } else {
+            (None, None, None)
+        };
 
         let device_type = match properties.device_type {
             vk::PhysicalDeviceType::INTEGRATED_GPU => GPUKind::Integrated,
@@ -121,13 +648,52 @@ pub fn retrieve_gpu_info_via_vk() -> Result<Vec<GPU>, Error> {
             .map(|heap| heap.size)
             .sum::<u64>();
 
+        // Query live memory pressure via `VK_EXT_memory_budget`. The extension
+        // fills `heapBudget[]`/`heapUsage[]` parallel to the heaps, so we sum
+        // the entries belonging to DEVICE_LOCAL heaps just like `vram` above.
+        let (vram_used, vram_budget) = if supports_properties2 && device_has_memory_budget(&instance, device) {
+            let mut budget_properties = vk::PhysicalDeviceMemoryBudgetPropertiesEXT::default();
+            let mut memory_properties2 =
+                vk::PhysicalDeviceMemoryProperties2::default().push_next(&mut budget_properties);
+            unsafe {
+                instance.get_physical_device_memory_properties2(device, &mut memory_properties2)
+            };
+
+            let (mut used, mut budget) = (0u64, 0u64);
+            for (i, heap) in memory_properties
+                .memory_heaps
+                .iter()
+                .take(memory_properties.memory_heap_count as usize)
+                .enumerate()
+            {
+                if heap.flags.contains(vk::MemoryHeapFlags::DEVICE_LOCAL) {
+                    used += budget_properties.heap_usage[i];
+                    budget += budget_properties.heap_budget[i];
+                }
+            }
+            (
+                Some(used / (1024 * 1024)),
+                Some(budget / (1024 * 1024)),
+            )
+        } else {
+            (None, None)
+        };
+
         // Populate GPU struct
         let gpu = GPU {
             kind: device_type,
             name: device_name,
             vendor: vendor_name,
+            vendor_id,
+            device_id,
             driver_version,
+            driver_version_raw,
+            driver_id,
+            driver_name,
+            driver_info,
             vram: vram_size / (1024 * 1024), // Convert to MB
+            vram_used,
+            vram_budget,
             clock_speed: None,               // Vulkan does not provide clock speed
             temperature: None,               // Vulkan does not provide temperature natively
         };
@@ -138,6 +704,231 @@ pub fn retrieve_gpu_info_via_vk() -> Result<Vec<GPU>, Error> {
     Ok(gpus)
 }
 
+/// Enrich an already-queried set of GPUs with vendor telemetry.
+///
+/// The base Vulkan query stays dependency-light; this optional pass fills in
+/// [`GPU::clock_speed`] and [`GPU::temperature`], which Vulkan does not expose,
+/// from vendor monitoring backends (NVML on NVIDIA, `hwmon` sysfs on AMD/Linux).
+/// Telemetry handles are matched to Vulkan devices by PCI vendor/device ID, and
+/// any field that cannot be read is left as `None`.
+///
+/// Compiled to a no-op unless the `telemetry` feature is enabled, so callers
+/// can always invoke it unconditionally.
+#[cfg(feature = "telemetry")]
+pub fn enrich_with_telemetry(gpus: &mut [GPU]) {
+    for gpu in gpus.iter_mut() {
+        match gpu.vendor.as_str() {
+            "NVIDIA" => telemetry::enrich_nvidia(gpu),
+            "AMD" => telemetry::enrich_amd(gpu),
+            _ => {}
+        }
+    }
+}
+
+/// No-op fallback when the `telemetry` feature is disabled.
+#[cfg(not(feature = "telemetry"))]
+pub fn enrich_with_telemetry(_gpus: &mut [GPU]) {}
+
+#[cfg(feature = "telemetry")]
+mod telemetry {
+    use super::GPU;
+
+    /// Read core clock and temperature from NVML and apply them to `gpu`.
+    pub(super) fn enrich_nvidia(gpu: &mut GPU) {
+        use nvml_wrapper::enum_wrappers::device::{Clock, TemperatureSensor};
+        use nvml_wrapper::Nvml;
+
+        let Ok(nvml) = Nvml::init() else { return };
+        let Ok(count) = nvml.device_count() else {
+            return;
+        };
+
+        for index in 0..count {
+            let Ok(device) = nvml.device_by_index(index) else {
+                continue;
+            };
+            // Match the NVML handle to the Vulkan device by PCI identity. NVML
+            // packs vendor and device into `pci_device_id` as
+            // `(device_id << 16) | vendor_id`.
+            let Ok(pci_info) = device.pci_info() else {
+                continue;
+            };
+            let vendor_id = pci_info.pci_device_id & 0xFFFF;
+            let device_id = pci_info.pci_device_id >> 16;
+            if vendor_id == gpu.vendor_id && device_id == gpu.device_id {
+                if let Ok(clock) = device.clock_info(Clock::Graphics) {
+                    gpu.clock_speed = Some(clock);
+                }
+                if let Ok(temp) = device.temperature(TemperatureSensor::Gpu) {
+                    gpu.temperature = Some(temp);
+                }
+                break;
+            }
+        }
+    }
+
+    /// Read core clock and temperature from `hwmon` sysfs on Linux.
+    #[cfg(target_os = "linux")]
+    pub(super) fn enrich_amd(gpu: &mut GPU) {
+        use std::fs;
+        use std::path::Path;
+
+        let Ok(entries) = fs::read_dir("/sys/class/hwmon") else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            // Only amdgpu-backed hwmon nodes carry the clock/temp we want.
+            if fs::read_to_string(path.join("name"))
+                .map(|name| name.trim() != "amdgpu")
+                .unwrap_or(true)
+            {
+                continue;
+            }
+
+            // Match the hwmon node to the Vulkan device by PCI identity via its
+            // backing `device/` symlink, so on a multi-GPU box each card gets
+            // its own clock/temp rather than the first node's.
+            let vendor_id = read_hex(&path.join("device/vendor"));
+            let device_id = read_hex(&path.join("device/device"));
+            if vendor_id != Some(gpu.vendor_id) || device_id != Some(gpu.device_id) {
+                continue;
+            }
+
+            if gpu.clock_speed.is_none() {
+                // `freq*_input` is reported in Hz; expose MHz like NVML does.
+                if let Some(hz) = read_u64(&path, "freq1_input") {
+                    gpu.clock_speed = Some((hz / 1_000_000) as u32);
+                }
+            }
+            if gpu.temperature.is_none() {
+                // `temp*_input` is reported in millidegrees Celsius.
+                if let Some(milli) = read_u64(&path, "temp1_input") {
+                    gpu.temperature = Some((milli / 1000) as u32);
+                }
+            }
+        }
+
+        fn read_u64(dir: &Path, file: &str) -> Option<u64> {
+            fs::read_to_string(dir.join(file))
+                .ok()
+                .and_then(|s| s.trim().parse::<u64>().ok())
+        }
+
+        // PCI `vendor`/`device` sysfs nodes are `0x`-prefixed hex (e.g. `0x1002`).
+        fn read_hex(path: &Path) -> Option<u32> {
+            let contents = fs::read_to_string(path).ok()?;
+            let trimmed = contents.trim();
+            let digits = trimmed.strip_prefix("0x").unwrap_or(trimmed);
+            u32::from_str_radix(digits, 16).ok()
+        }
+    }
+
+    /// AMD telemetry is only wired up for Linux `hwmon`; elsewhere it degrades
+    /// to leaving the fields `None`.
+    #[cfg(not(target_os = "linux"))]
+    pub(super) fn enrich_amd(_gpu: &mut GPU) {}
+}
+
+/// Whether a physical device advertises a given device extension.
+fn device_has_extension(
+    instance: &ash::Instance,
+    device: vk::PhysicalDevice,
+    name: &CStr,
+) -> bool {
+    let extensions = match unsafe { instance.enumerate_device_extension_properties(device) } {
+        Ok(extensions) => extensions,
+        Err(_) => return false,
+    };
+    extensions
+        .iter()
+        .any(|ext| unsafe { CStr::from_ptr(ext.extension_name.as_ptr()) } == name)
+}
+
+/// Whether a physical device advertises `VK_EXT_memory_budget`.
+fn device_has_memory_budget(instance: &ash::Instance, device: vk::PhysicalDevice) -> bool {
+    device_has_extension(instance, device, ash::ext::memory_budget::NAME)
+}
+
+/// Whether a physical device advertises `VK_KHR_driver_properties`.
+fn device_has_driver_properties(instance: &ash::Instance, device: vk::PhysicalDevice) -> bool {
+    device_has_extension(instance, device, ash::khr::driver_properties::NAME)
+}
+
+/// Pick the preferred GPU from an already-enumerated slice.
+///
+/// By default devices are ranked by kind (Discrete > Integrated > Virtual >
+/// CPU > Unknown) and the highest-priority one is returned. When
+/// `override_index` is `Some` — or the [`DEVICE_INDEX_ENV`] environment variable
+/// is set — the ranking is bypassed and that physical-device index is returned
+/// directly, yielding [`Error::DeviceIndexOutOfRange`] if it does not exist or
+/// [`Error::InvalidDeviceIndexOverride`] if the env value is not a valid index.
+///
+/// Returns `Ok(None)` only when `gpus` is empty and no override is requested.
+pub fn select_preferred_gpu(
+    gpus: &[GPU],
+    override_index: Option<usize>,
+) -> Result<Option<&GPU>, Error> {
+    let forced = match override_index {
+        Some(index) => Some(index),
+        None => match std::env::var(DEVICE_INDEX_ENV) {
+            // A set-but-unparseable env override is a misconfiguration: surface
+            // it rather than silently falling back to type-priority ranking.
+            Ok(raw) => Some(raw.trim().parse::<usize>().map_err(|_| {
+                Error::InvalidDeviceIndexOverride(DEVICE_INDEX_ENV, raw)
+            })?),
+            Err(_) => None,
+        },
+    };
+
+    if let Some(index) = forced {
+        return gpus
+            .get(index)
+            .map(Some)
+            .ok_or(Error::DeviceIndexOutOfRange(index));
+    }
+
+    Ok(gpus.iter().max_by_key(|gpu| gpu.kind.priority()))
+}
+
+/// Retrieve GPU info via Vulkan and return the preferred device in one call.
+///
+/// Convenience wrapper around [`retrieve_gpu_info_via_vk`] +
+/// [`select_preferred_gpu`]; see the latter for override semantics.
+pub fn retrieve_preferred_gpu_via_vk(override_index: Option<usize>) -> Result<Option<GPU>, Error> {
+    let gpus = retrieve_gpu_info_via_vk()?;
+    Ok(select_preferred_gpu(&gpus, override_index)?.cloned())
+}
+
+/// Format a raw Vulkan `driverVersion` into a human-readable string.
+///
+/// The encoding is not standardized across vendors: NVIDIA packs four fields
+/// and Intel on Windows uses a two-field layout, while everyone else (AMD,
+/// Mesa, …) follows the generic `major.minor.patch` Vulkan convention.
+fn format_driver_version(vendor_id: u32, version: u32) -> String {
+    match vendor_id {
+        // NVIDIA (0x10DE): 10.8.8.6 bit layout.
+        0x10DE => format!(
+            "{}.{}.{}.{}",
+            (version >> 22) & 0x3FF,
+            (version >> 14) & 0xFF,
+            (version >> 6) & 0xFF,
+            version & 0x3F
+        ),
+        // Intel on Windows (0x8086): 18.14 bit layout. Intel on Linux (Mesa)
+        // uses the generic scheme, so this branch only applies on Windows.
+        #[cfg(windows)]
+        0x8086 => format!("{}.{}", version >> 14, version & 0x3FFF),
+        // AMD/Mesa and everyone else: generic Vulkan major.minor.patch.
+        _ => format!(
+            "{}.{}.{}",
+            (version >> 22) & 0x3FF,
+            (version >> 12) & 0x3FF,
+            version & 0xFFF
+        ),
+    }
+}
+
 // pub fn retrieve_gpu_info_via_gl() -> Result<Vec<GPU>, Error> {
 //     // Create a headless context
 //     let event_loop = winit::event_loop::EventLoop::new();
@@ -231,6 +1022,26 @@ mod tests {
     use super::*;
     use test_log::test;
 
+    fn gpu(kind: GPUKind, vendor_id: u32, device_id: u32, driver_version_raw: u32) -> GPU {
+        GPU {
+            kind,
+            name: "Test GPU".to_string(),
+            vendor: "Test".to_string(),
+            vendor_id,
+            device_id,
+            driver_version: String::new(),
+            driver_version_raw,
+            driver_id: None,
+            driver_name: None,
+            driver_info: None,
+            vram: 0,
+            vram_used: None,
+            vram_budget: None,
+            clock_speed: None,
+            temperature: None,
+        }
+    }
+
     #[test]
     fn test_retrieve_gpu_info_via_vk() {
         let result = retrieve_gpu_info_via_vk();
@@ -240,4 +1051,110 @@ mod tests {
             Err(e) => e.is_vulkan_not_supported(),
         });
     }
+
+    #[test]
+    fn test_format_driver_version_nvidia() {
+        // NVIDIA 10.8.8.6 layout: fields (526, 36, 1, 2) packed together.
+        let raw = (526 << 22) | (36 << 14) | (1 << 6) | 2;
+        assert_eq!(format_driver_version(0x10DE, raw), "526.36.1.2");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_format_driver_version_intel_windows() {
+        // Intel-on-Windows 18.14 layout: fields (101, 4644).
+        let raw = (101 << 14) | 4644;
+        assert_eq!(format_driver_version(0x8086, raw), "101.4644");
+    }
+
+    #[test]
+    fn test_format_driver_version_generic() {
+        // AMD/Mesa and everyone else use the generic major.minor.patch layout.
+        let raw = (23 << 22) | (1 << 12) | 99;
+        assert_eq!(format_driver_version(0x1002, raw), "23.1.99");
+        // Intel on non-Windows also falls through to the generic scheme.
+        #[cfg(not(windows))]
+        assert_eq!(format_driver_version(0x8086, raw), "23.1.99");
+    }
+
+    // Ranking, explicit override, out-of-range and env override are exercised in
+    // one test so the process-global `DEVICE_INDEX_ENV` can't race other tests.
+    #[test]
+    fn test_select_preferred_gpu() {
+        let gpus = vec![
+            gpu(GPUKind::CPU, 0, 0, 0),
+            gpu(GPUKind::Integrated, 0, 0, 0),
+            gpu(GPUKind::Discrete, 0, 0, 0),
+            gpu(GPUKind::Virtual, 0, 0, 0),
+        ];
+
+        // Default ranking returns the highest-priority (Discrete) device.
+        let selected = select_preferred_gpu(&gpus, None).unwrap().unwrap();
+        assert_eq!(selected.kind, GPUKind::Discrete);
+
+        // An explicit override bypasses the ranking.
+        let selected = select_preferred_gpu(&gpus, Some(0)).unwrap().unwrap();
+        assert_eq!(selected.kind, GPUKind::CPU);
+
+        // Out-of-range overrides error instead of silently falling back.
+        assert!(matches!(
+            select_preferred_gpu(&gpus, Some(9)),
+            Err(Error::DeviceIndexOutOfRange(9))
+        ));
+
+        // An empty slice with no override yields `None`.
+        assert!(select_preferred_gpu(&[], None).unwrap().is_none());
+
+        // The environment variable forces an index when no argument is given.
+        std::env::set_var(DEVICE_INDEX_ENV, "1");
+        let selected = select_preferred_gpu(&gpus, None).unwrap().unwrap();
+        assert_eq!(selected.kind, GPUKind::Integrated);
+
+        // A set-but-unparseable value is a hard error, not a silent fallback.
+        std::env::set_var(DEVICE_INDEX_ENV, "not-a-number");
+        assert!(matches!(
+            select_preferred_gpu(&gpus, None),
+            Err(Error::InvalidDeviceIndexOverride(DEVICE_INDEX_ENV, _))
+        ));
+        std::env::remove_var(DEVICE_INDEX_ENV);
+    }
+
+    #[test]
+    fn test_version_cmp_eval() {
+        assert!(VersionCmp::Lt.eval(1, 2));
+        assert!(!VersionCmp::Lt.eval(2, 2));
+        assert!(VersionCmp::Ge.eval(2, 2));
+        assert!(VersionCmp::Ne.eval(1, 2));
+        assert!(VersionCmp::Eq.eval(2, 2));
+        assert!(VersionCmp::Gt.eval(3, 2));
+    }
+
+    #[test]
+    fn test_gpu_matches() {
+        let device = gpu(GPUKind::Discrete, 0x10DE, 0x2204, 100);
+
+        // An empty rule matches everything.
+        assert!(device.matches(&GpuMatchRule::default()));
+
+        // A known-bad combination: NVIDIA device in an id range on an old driver.
+        let blocklist = GpuMatchRule {
+            vendor_id: Some(0x10DE),
+            device_id_range: Some((0x2200, 0x2300)),
+            kind: Some(GPUKind::Discrete),
+            driver_version: Some((VersionCmp::Lt, 200)),
+        };
+        assert!(device.matches(&blocklist));
+
+        // A newer driver escapes the blocklist.
+        let newer = gpu(GPUKind::Discrete, 0x10DE, 0x2204, 300);
+        assert!(!newer.matches(&blocklist));
+
+        // A device outside the id range does not match.
+        let other = gpu(GPUKind::Discrete, 0x10DE, 0x1000, 100);
+        assert!(!other.matches(&blocklist));
+
+        // A different vendor does not match.
+        let amd = gpu(GPUKind::Discrete, 0x1002, 0x2204, 100);
+        assert!(!amd.matches(&blocklist));
+    }
 }